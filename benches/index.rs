@@ -0,0 +1,64 @@
+//! Criterion Benchmarks for indexed-loop codegen with and without the use of the invariant_index! macro.
+#![allow(clippy::needless_range_loop)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use invariant_rs::invariant_index;
+
+#[inline(never)]
+fn sum_with_invariant(slice: &[u64]) -> u64 {
+    let mut acc = 0u64;
+    for i in 0..slice.len() {
+        invariant_index!(i, slice);
+        acc = acc.wrapping_add(slice[i]);
+    }
+    acc
+}
+
+#[inline(never)]
+fn sum_without_invariant(slice: &[u64]) -> u64 {
+    let mut acc = 0u64;
+    for i in 0..slice.len() {
+        acc = acc.wrapping_add(slice[i]);
+    }
+    acc
+}
+
+#[inline]
+fn sum_with_invariant_inlined(slice: &[u64]) -> u64 {
+    let mut acc = 0u64;
+    for i in 0..slice.len() {
+        invariant_index!(i, slice);
+        acc = acc.wrapping_add(slice[i]);
+    }
+    acc
+}
+
+#[inline]
+fn sum_without_invariant_inlined(slice: &[u64]) -> u64 {
+    let mut acc = 0u64;
+    for i in 0..slice.len() {
+        acc = acc.wrapping_add(slice[i]);
+    }
+    acc
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let data: [u64; 10_000] = core::array::from_fn(|i| i as u64);
+
+    c.bench_function("sum_without_invariant", |b| {
+        b.iter(|| sum_without_invariant(black_box(&data)))
+    });
+    c.bench_function("sum_with_invariant", |b| {
+        b.iter(|| sum_with_invariant(black_box(&data)))
+    });
+    c.bench_function("sum_without_invariant_inlined", |b| {
+        b.iter(|| sum_without_invariant_inlined(black_box(&data)))
+    });
+    c.bench_function("sum_with_invariant_inlined", |b| {
+        b.iter(|| sum_with_invariant_inlined(black_box(&data)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+
+criterion_main!(benches);
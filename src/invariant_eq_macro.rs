@@ -57,8 +57,37 @@
 macro_rules! invariant_eq {
     ($left:expr, $right:expr $(,)?) => {
         {
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if $left != $right {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), not(any(feature = "log", feature = "defmt"))))]
             debug_assert_eq!($left, $right);
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), any(feature = "log", feature = "defmt")))]
+            {
+                let (left, right) = (&$left, &$right);
+                if left != right {
+                    $crate::__report_invariant!("assertion failed: `(left == right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    debug_assert_eq!(left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if $left != $right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                let (left, right) = (&$left, &$right);
+                if left != right {
+                    $crate::__report_invariant!("assertion failed: `(left == right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    panic!("assertion failed: `(left == right)`\n  left: `{}`,\n right: `{}`", left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
             {
                 if $left != $right {
                     unsafe {
@@ -70,8 +99,37 @@ macro_rules! invariant_eq {
     };
     ($left:expr, $right:expr, $($arg:tt)+) => {
         {
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if $left != $right {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), not(any(feature = "log", feature = "defmt"))))]
             debug_assert_eq!($left, $right, $($arg)+);
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), any(feature = "log", feature = "defmt")))]
+            {
+                let (left, right) = (&$left, &$right);
+                if left != right {
+                    $crate::__report_invariant!($($arg)+);
+                    debug_assert_eq!(left, right, $($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if $left != $right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                let (left, right) = (&$left, &$right);
+                if left != right {
+                    $crate::__report_invariant!($($arg)+);
+                    panic!($($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
             {
                 if $left != $right {
                     unsafe {
@@ -93,7 +151,8 @@ mod tests {
 
     #[test]
     #[cfg(debug_assertions)]
-    #[should_panic]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
     fn test_invariant_eq_panic() {
         invariant_eq!(1, 2);
     }
@@ -9,15 +9,36 @@
 macro_rules! invariant_ge {
     ($left:expr, $right:expr $(,)?) => {
         {
-            #[cfg(debug_assertions)]
+            let (left, right) = (&$left, &$right);
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
             {
-                if $left < $right {
-                    panic!("assertion failed: `(left >= right)`\n  left: `{}`,\n right: `{}`", $left, $right);
+                if left < right {
+                    panic!();
                 }
             }
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
             {
-                if $left < $right {
+                if left < right {
+                    $crate::__report_invariant!("assertion failed: `(left >= right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    panic!("assertion failed: `(left >= right)`\n  left: `{}`,\n right: `{}`", left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if left < right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                if left < right {
+                    $crate::__report_invariant!("assertion failed: `(left >= right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    panic!("assertion failed: `(left >= right)`\n  left: `{}`,\n right: `{}`", left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
+            {
+                if left < right {
                     unsafe {
                         core::hint::unreachable_unchecked();
                     }
@@ -27,15 +48,36 @@ macro_rules! invariant_ge {
     };
     ($left:expr, $right:expr, $($arg:tt)+) => {
         {
-            #[cfg(debug_assertions)]
+            let (left, right) = (&$left, &$right);
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if left < right {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
+            {
+                if left < right {
+                    $crate::__report_invariant!($($arg)+);
+                    panic!($($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if left < right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
             {
-                if $left < $right {
+                if left < right {
+                    $crate::__report_invariant!($($arg)+);
                     panic!($($arg)+);
                 }
             }
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
             {
-                if $left < $right {
+                if left < right {
                     unsafe {
                         core::hint::unreachable_unchecked();
                     }
@@ -56,7 +98,8 @@ mod tests {
 
     #[test]
     #[cfg(debug_assertions)]
-    #[should_panic]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
     fn test_invariant_ge_panic() {
         let a = 1;
         let b = 2;
@@ -0,0 +1,211 @@
+//! Macros to check index and range invariants in debug mode and to optimize them away in release mode.
+
+/// A debug assert macro to check that an index is within the bounds of a slice.
+///
+/// # Implementative details
+///
+/// In debug mode [`invariant_index`] asserts that `index < slice.len()` with a
+/// descriptive panic, while in release mode it lowers to the same pattern as the
+/// other macros in this crate: `if !(index < slice.len()) { core::hint::unreachable_unchecked() }`.
+/// The length expression `slice.len()` is bound to a local exactly once and the
+/// check is positioned immediately before the macro returns, so the compiler can
+/// propagate the `assume` fact forward and elide the bounds check of a following
+/// `slice[index]` access within the same function.
+///
+/// # Example
+///
+/// In the following code the bounds check of `slice[i]` is elided in release mode,
+/// because the compiler is told that `i` is a valid index. Note that we add the
+/// `#[inline(never)]` attribute to prevent the compiler from inlining the functions,
+/// so we can see clearly the generated assembly code.
+///
+/// ```rust
+/// use invariant_rs::invariant_index;
+///
+/// #[inline(never)]
+/// pub fn get(slice: &[u8], i: usize) -> u8 {
+///     invariant_index!(i, slice);
+///     slice[i]
+/// }
+/// ```
+///
+/// # Safety
+/// Just like [`invariant!`], using this macro in release mode assumes the invariant holds,
+/// so make sure to check the condition thoroughly in debug mode.
+#[macro_export]
+macro_rules! invariant_index {
+    ($index:expr, $slice:expr $(,)?) => {
+        {
+            let index = $index;
+            let len = $slice.len();
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if !(index < len) {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
+            {
+                if !(index < len) {
+                    $crate::__report_invariant!("assertion failed: index out of bounds: the len is `{}` but the index is `{}`", len, index);
+                    panic!("assertion failed: index out of bounds: the len is `{}` but the index is `{}`", len, index);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if !(index < len) {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                if !(index < len) {
+                    $crate::__report_invariant!("assertion failed: index out of bounds: the len is `{}` but the index is `{}`", len, index);
+                    panic!("assertion failed: index out of bounds: the len is `{}` but the index is `{}`", len, index);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
+            {
+                if !(index < len) {
+                    unsafe {
+                        core::hint::unreachable_unchecked();
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// A debug assert macro to check that a value lies within the half-open range `[lo, hi)`.
+///
+/// # Implementative details
+///
+/// In debug mode [`invariant_range`] asserts that `lo <= value && value < hi` with a
+/// descriptive panic, while in release mode it lowers to the same pattern as the
+/// other macros in this crate, informing the compiler that the value is in range so
+/// that subsequent range-dependent accesses can be optimized. The `lo`, `hi` and
+/// `value` expressions are each bound to a local exactly once.
+///
+/// # Safety
+/// Just like [`invariant!`], using this macro in release mode assumes the invariant holds,
+/// so make sure to check the condition thoroughly in debug mode.
+#[macro_export]
+macro_rules! invariant_range {
+    ($lo:expr, $hi:expr, $value:expr $(,)?) => {
+        {
+            let (lo, hi, value) = ($lo, $hi, $value);
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if !(lo <= value && value < hi) {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
+            {
+                if !(lo <= value && value < hi) {
+                    $crate::__report_invariant!("assertion failed: `(lo <= value < hi)`\n   lo: `{}`,\n value: `{}`,\n   hi: `{}`", lo, value, hi);
+                    panic!("assertion failed: `(lo <= value < hi)`\n   lo: `{}`,\n value: `{}`,\n   hi: `{}`", lo, value, hi);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if !(lo <= value && value < hi) {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                if !(lo <= value && value < hi) {
+                    $crate::__report_invariant!("assertion failed: `(lo <= value < hi)`\n   lo: `{}`,\n value: `{}`,\n   hi: `{}`", lo, value, hi);
+                    panic!("assertion failed: `(lo <= value < hi)`\n   lo: `{}`,\n value: `{}`,\n   hi: `{}`", lo, value, hi);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
+            {
+                if !(lo <= value && value < hi) {
+                    unsafe {
+                        core::hint::unreachable_unchecked();
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    #[test]
+    fn test_invariant_index() {
+        let slice = [1, 2, 3];
+        invariant_index!(0, slice);
+        invariant_index!(2, slice);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
+    fn test_invariant_index_panic() {
+        let slice = [1, 2, 3];
+        invariant_index!(3, slice);
+    }
+
+    #[test]
+    fn test_invariant_range() {
+        invariant_range!(0, 10, 5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
+    fn test_invariant_range_panic() {
+        invariant_range!(0, 10, 10);
+    }
+
+    /// A slice-like probe whose `len` increments a counter, used to prove that the
+    /// length expression is evaluated exactly once.
+    struct LenProbe<'a> {
+        calls: &'a Cell<u32>,
+        len: usize,
+    }
+
+    impl LenProbe<'_> {
+        fn len(&self) -> usize {
+            self.calls.set(self.calls.get() + 1);
+            self.len
+        }
+    }
+
+    #[test]
+    fn test_invariant_index_evaluates_slice_once() {
+        let calls = Cell::new(0);
+        let probe = LenProbe {
+            calls: &calls,
+            len: 4,
+        };
+        invariant_index!(1, probe);
+        assert_eq!(
+            calls.get(),
+            1,
+            "the slice length expression must be evaluated exactly once"
+        );
+    }
+
+    #[test]
+    fn test_invariant_range_evaluates_value_once() {
+        let calls = Cell::new(0);
+        let next = || {
+            calls.set(calls.get() + 1);
+            5
+        };
+        invariant_range!(0, 10, next());
+        assert_eq!(
+            calls.get(),
+            1,
+            "the range value expression must be evaluated exactly once"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! A macro to check invariants lower than in debug mode and to optimize them away in release mode.
+
+/// A debug assert macro to check whether an element is lower than another in debug mode and optimize them away in release mode.
+///
+/// # Safety
+/// Just like [`invariant!`], using this macro in release mode assumes the invariant holds,
+/// so make sure to check the condition thoroughly in debug mode.
+#[macro_export]
+macro_rules! invariant_lt {
+    ($left:expr, $right:expr $(,)?) => {
+        {
+            let (left, right) = (&$left, &$right);
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if left >= right {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
+            {
+                if left >= right {
+                    $crate::__report_invariant!("assertion failed: `(left < right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    panic!("assertion failed: `(left < right)`\n  left: `{}`,\n right: `{}`", left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if left >= right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                if left >= right {
+                    $crate::__report_invariant!("assertion failed: `(left < right)`\n  left: `{}`,\n right: `{}`", left, right);
+                    panic!("assertion failed: `(left < right)`\n  left: `{}`,\n right: `{}`", left, right);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
+            {
+                if left >= right {
+                    unsafe {
+                        core::hint::unreachable_unchecked();
+                    }
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        {
+            let (left, right) = (&$left, &$right);
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if left >= right {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs")))]
+            {
+                if left >= right {
+                    $crate::__report_invariant!($($arg)+);
+                    panic!($($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if left >= right {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                if left >= right {
+                    $crate::__report_invariant!($($arg)+);
+                    panic!($($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
+            {
+                if left >= right {
+                    unsafe {
+                        core::hint::unreachable_unchecked();
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_invariant_lt() {
+        let a = 1;
+        let b = 2;
+        invariant_lt!(a, b);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
+    fn test_invariant_lt_panic() {
+        let a = 2;
+        let b = 1;
+        invariant_lt!(a, b);
+    }
+}
@@ -63,8 +63,37 @@
 macro_rules! invariant {
     ($cond:expr $(,)?) => {
         {
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if !($cond) {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), not(any(feature = "log", feature = "defmt"))))]
             debug_assert!($cond);
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), any(feature = "log", feature = "defmt")))]
+            {
+                let cond = $cond;
+                if !cond {
+                    $crate::__report_invariant!("assertion failed: {}", core::stringify!($cond));
+                    debug_assert!(cond, "assertion failed: {}", core::stringify!($cond));
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if !($cond) {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                let cond = $cond;
+                if !cond {
+                    $crate::__report_invariant!("assertion failed: {}", core::stringify!($cond));
+                    panic!("assertion failed: {}", core::stringify!($cond));
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
             {
                 if !($cond) {
                     unsafe{
@@ -76,8 +105,37 @@ macro_rules! invariant {
     };
     ($cond:expr, $($arg:tt)+) => {
         {
+            #[cfg(all(debug_assertions, feature = "no-panic-msgs"))]
+            {
+                if !($cond) {
+                    panic!();
+                }
+            }
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), not(any(feature = "log", feature = "defmt"))))]
             debug_assert!($cond, $($arg)+);
-            #[cfg(not(debug_assertions))]
+            #[cfg(all(debug_assertions, not(feature = "no-panic-msgs"), any(feature = "log", feature = "defmt")))]
+            {
+                let cond = $cond;
+                if !cond {
+                    $crate::__report_invariant!($($arg)+);
+                    debug_assert!(cond, $($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), feature = "observe"))]
+            {
+                if !($cond) {
+                    $crate::observe::report_violation();
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), feature = "harden"))]
+            {
+                let cond = $cond;
+                if !cond {
+                    $crate::__report_invariant!($($arg)+);
+                    panic!($($arg)+);
+                }
+            }
+            #[cfg(all(not(debug_assertions), not(feature = "observe"), not(feature = "harden")))]
             {
                 if !($cond) {
                     unsafe{
@@ -99,7 +157,8 @@ mod tests {
 
     #[test]
     #[cfg(debug_assertions)]
-    #[should_panic]
+    #[cfg_attr(not(feature = "no-panic-msgs"), should_panic(expected = "assertion"))]
+    #[cfg_attr(feature = "no-panic-msgs", should_panic)]
     fn test_invariant_panic() {
         invariant!(false);
     }
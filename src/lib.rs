@@ -11,10 +11,18 @@
 mod invariant_eq_macro;
 mod invariant_ge_macro;
 mod invariant_gt_macro;
+mod invariant_index_macro;
 mod invariant_le_macro;
 mod invariant_lt_macro;
 mod invariant_macro;
 mod invariant_ne_macro;
+mod report_macro;
+
+#[cfg(feature = "observe")]
+pub mod observe;
+
+#[cfg(feature = "observe")]
+pub use observe::set_violation_observer;
 
 /// Module re-exporting the invariant check macros.
 pub mod prelude {
@@ -22,7 +30,9 @@ pub mod prelude {
     pub use crate::invariant_eq;
     pub use crate::invariant_ge;
     pub use crate::invariant_gt;
+    pub use crate::invariant_index;
     pub use crate::invariant_le;
     pub use crate::invariant_lt;
     pub use crate::invariant_ne;
+    pub use crate::invariant_range;
 }
@@ -0,0 +1,46 @@
+//! Production `observe` mode: report invariant violations instead of assuming them.
+//!
+//! When the `observe` feature is enabled the release expansion of every macro keeps
+//! evaluating its condition and, on failure, reports the violation through
+//! [`report_violation`] before continuing with the real value, rather than calling
+//! [`core::hint::unreachable_unchecked`]. This lets a team roll out invariants
+//! gradually: run the `observe` build in production to confirm a predicate truly
+//! never fires before flipping to the fully-optimized mode that trusts it.
+
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// The signature of a violation observer.
+type Observer = fn(&'static Location<'static>);
+
+/// The registered observer, stored as an erased data pointer.
+///
+/// A null value means no observer has been registered.
+static OBSERVER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Registers the function invoked whenever an invariant is violated in `observe` builds.
+///
+/// The observer receives the [`Location`] of the violated invariant, allowing callers to
+/// aggregate per-call-site hit counts the way coverage tooling tags lines. Registering a
+/// new observer replaces any previously registered one.
+pub fn set_violation_observer(observer: Observer) {
+    OBSERVER.store(observer as *mut (), Ordering::SeqCst);
+}
+
+/// Reports a violated invariant to the registered observer, if any.
+///
+/// This is an implementation detail invoked by the macros in `observe` builds. The
+/// `#[track_caller]` attribute makes [`Location::caller`] resolve to the call site of the
+/// violated macro, which is forwarded to the observer.
+#[doc(hidden)]
+#[track_caller]
+pub fn report_violation() {
+    let observer = OBSERVER.load(Ordering::SeqCst);
+    if !observer.is_null() {
+        // SAFETY: `observer` is non-null, so it is a function pointer previously stored by
+        // `set_violation_observer`. A `transmute` is required because there is no stable
+        // `as` cast from a data pointer back to a function pointer.
+        let observer: Observer = unsafe { core::mem::transmute(observer) };
+        observer(Location::caller());
+    }
+}
@@ -0,0 +1,27 @@
+//! Internal reporting shim shared by every invariant macro.
+
+/// Emits a violated-invariant message through the enabled telemetry backends.
+///
+/// This is an implementation detail shared by all of the public invariant
+/// macros so that they route their failure message through a single code path.
+/// It forwards its arguments to [`log::error!`] when the `log` feature is
+/// enabled and to [`defmt::error!`] when the `defmt` feature is enabled, and
+/// expands to nothing when neither feature is active. The expansion is
+/// therefore empty in the default configuration, leaving the macros that use
+/// it byte-for-byte identical to a build without this shim.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __report_invariant {
+    ($($arg:tt)+) => {
+        {
+            #[cfg(feature = "log")]
+            {
+                ::log::error!($($arg)+);
+            }
+            #[cfg(feature = "defmt")]
+            {
+                ::defmt::error!($($arg)+);
+            }
+        }
+    };
+}